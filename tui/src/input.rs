@@ -0,0 +1,180 @@
+//! A minimal caret-aware text field, used by the free-text wizard steps
+//! (`Step::SrcPath`, `Step::YtUrl`) so editing mid-string doesn't require
+//! retyping everything after the typo.
+
+/// A single-line text buffer with an editable caret position, tracked in
+/// characters (not bytes) so it stays correct for non-ASCII input.
+pub struct TextField {
+    chars: Vec<char>,
+    caret: usize,
+}
+
+impl TextField {
+    pub fn new(value: impl Into<String>) -> Self {
+        let chars: Vec<char> = value.into().chars().collect();
+        let caret = chars.len();
+        Self { chars, caret }
+    }
+
+    pub fn value(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// The text split around the caret, for rendering: characters before
+    /// it, the character it sits on (if any), and the rest.
+    pub fn split_at_caret(&self) -> (String, Option<char>, String) {
+        let before: String = self.chars[..self.caret].iter().collect();
+        let at = self.chars.get(self.caret).copied();
+        let after_start = if at.is_some() { self.caret + 1 } else { self.caret };
+        let after: String = self.chars[after_start..].iter().collect();
+        (before, at, after)
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.chars.insert(self.caret, c);
+        self.caret += 1;
+    }
+
+    pub fn insert_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.insert(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if self.caret > 0 {
+            self.chars.remove(self.caret - 1);
+            self.caret -= 1;
+        }
+    }
+
+    pub fn delete_forward(&mut self) {
+        if self.caret < self.chars.len() {
+            self.chars.remove(self.caret);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.caret = self.caret.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.caret = (self.caret + 1).min(self.chars.len());
+    }
+
+    pub fn move_home(&mut self) {
+        self.caret = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.caret = self.chars.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Caret position, derived from `split_at_caret` since `TextField` has
+    /// no direct accessor (the caret itself is private, rendering-only
+    /// state).
+    fn caret_of(field: &TextField) -> usize {
+        field.split_at_caret().0.chars().count()
+    }
+
+    #[test]
+    fn new_starts_with_caret_at_the_end() {
+        let field = TextField::new("hello");
+        assert_eq!(field.value(), "hello");
+        assert_eq!(caret_of(&field), 5);
+    }
+
+    #[test]
+    fn insert_moves_caret_past_the_inserted_char() {
+        let mut field = TextField::new("helo");
+        field.move_left();
+        field.move_left();
+        field.insert('l');
+        assert_eq!(field.value(), "hello");
+        assert_eq!(caret_of(&field), 3);
+    }
+
+    #[test]
+    fn insert_str_inserts_each_char_at_the_caret() {
+        let mut field = TextField::new("ac");
+        field.move_left();
+        field.insert_str("b");
+        assert_eq!(field.value(), "abc");
+        assert_eq!(caret_of(&field), 2);
+    }
+
+    #[test]
+    fn backspace_removes_the_char_before_the_caret() {
+        let mut field = TextField::new("hello");
+        field.backspace();
+        assert_eq!(field.value(), "hell");
+        assert_eq!(caret_of(&field), 4);
+    }
+
+    #[test]
+    fn backspace_at_start_is_a_no_op() {
+        let mut field = TextField::new("hello");
+        field.move_home();
+        field.backspace();
+        assert_eq!(field.value(), "hello");
+        assert_eq!(caret_of(&field), 0);
+    }
+
+    #[test]
+    fn delete_forward_removes_the_char_under_the_caret() {
+        let mut field = TextField::new("hello");
+        field.move_home();
+        field.delete_forward();
+        assert_eq!(field.value(), "ello");
+        assert_eq!(caret_of(&field), 0);
+    }
+
+    #[test]
+    fn delete_forward_at_end_is_a_no_op() {
+        let mut field = TextField::new("hello");
+        field.delete_forward();
+        assert_eq!(field.value(), "hello");
+        assert_eq!(caret_of(&field), 5);
+    }
+
+    #[test]
+    fn move_left_clamps_at_zero() {
+        let mut field = TextField::new("hi");
+        field.move_home();
+        field.move_left();
+        assert_eq!(caret_of(&field), 0);
+    }
+
+    #[test]
+    fn move_right_clamps_at_the_end() {
+        let mut field = TextField::new("hi");
+        field.move_right();
+        assert_eq!(caret_of(&field), 2);
+    }
+
+    #[test]
+    fn split_at_caret_reports_before_at_and_after() {
+        let mut field = TextField::new("hello");
+        field.move_home();
+        field.move_right();
+        field.move_right();
+        let (before, at, after) = field.split_at_caret();
+        assert_eq!(before, "he");
+        assert_eq!(at, Some('l'));
+        assert_eq!(after, "lo");
+    }
+
+    #[test]
+    fn split_at_caret_at_end_has_no_char_under_it() {
+        let field = TextField::new("hi");
+        let (before, at, after) = field.split_at_caret();
+        assert_eq!(before, "hi");
+        assert_eq!(at, None);
+        assert_eq!(after, "");
+    }
+}
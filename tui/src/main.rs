@@ -1,8 +1,13 @@
-use std::io;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader};
 use std::path::PathBuf;
-use std::time::Duration;
+use std::process::{Child, Command as ChildCommand, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
+use arboard::Clipboard;
 use crossterm::event::{
     self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
 };
@@ -10,7 +15,6 @@ use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
-use once_cell::sync::Lazy;
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
@@ -18,40 +22,48 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{List, ListItem, Paragraph, Wrap};
 use ratatui::Terminal;
 
-static LANG_OPTIONS: Lazy<Vec<(&str, &str)>> = Lazy::new(|| {
-    vec![
-        ("Chinese", "zh"),
-        ("English", "en"),
-        ("Japanese", "ja"),
-        ("Korean", "ko"),
-        ("Spanish", "es"),
-        ("French", "fr"),
-        ("German", "de"),
-        ("Portuguese", "pt"),
-        ("No translation", ""),
-    ]
-});
+mod input;
+mod langs;
+mod profile;
+mod ytprobe;
+use input::TextField;
+use langs::{filter_langs, LANG_OPTIONS};
+use profile::Profile;
+use serde::{Deserialize, Serialize};
+use ytprobe::{spawn_probe, YtInfo, YtProbeState};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Maximum number of output lines kept in the scrollback ring buffer.
+const MAX_LOG_LINES: usize = 2000;
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 enum SourceMode {
     Local,
     YouTube,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 enum BurnUse {
     Translated,
     Original,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 enum BurnFormat {
     Mp4,
     Webm,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AudioFormat {
+    Mp3,
+    M4a,
+    Wav,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Step {
+    ProfilePicker,
     Mode,
     SrcPath,
     YtUrl,
@@ -60,42 +72,296 @@ enum Step {
     BurnIn,
     BurnUse,
     BurnFormat,
+    AudioExtract,
+    AudioFormat,
     Summary,
+    Running,
+}
+
+/// State for the child process spawned from the `Summary` step, plus the
+/// scrolling log pane that mirrors its output.
+struct RunState {
+    child: Option<Child>,
+    output_rx: Receiver<String>,
+    lines: VecDeque<String>,
+    scroll: usize,
+    started_at: Instant,
+    exit_code: Option<i32>,
+    spinner_frame: usize,
+}
+
+impl RunState {
+    /// Spawn `parts[0]` with `parts[1..]` as argv, without ever handing the
+    /// command through a shell. Keeping this argv-based (rather than
+    /// re-joining `parts` into a string and running `sh -c`/`cmd /C`) means
+    /// user-controlled text such as `src_path`/`yt_url` is never re-parsed
+    /// for shell metacharacters.
+    fn spawn(parts: &[String], dir: Option<&PathBuf>) -> io::Result<Self> {
+        let (program, args) = parts
+            .split_first()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "empty command"))?;
+        let mut cmd = ChildCommand::new(program);
+        cmd.args(args);
+        if let Some(dir) = dir {
+            cmd.current_dir(dir);
+        }
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let mut child = cmd.spawn()?;
+        let (tx, output_rx) = mpsc::channel();
+
+        if let Some(stdout) = child.stdout.take() {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        if let Some(stderr) = child.stderr.take() {
+            thread::spawn(move || {
+                for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        Ok(Self {
+            child: Some(child),
+            output_rx,
+            lines: VecDeque::with_capacity(MAX_LOG_LINES),
+            scroll: 0,
+            started_at: Instant::now(),
+            exit_code: None,
+            spinner_frame: 0,
+        })
+    }
+
+    fn push_line(&mut self, line: String) {
+        if self.lines.len() == MAX_LOG_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+
+    /// Drain any output produced since the last tick and poll for exit.
+    fn tick(&mut self) {
+        while let Ok(line) = self.output_rx.try_recv() {
+            self.push_line(line);
+        }
+        self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+
+        if self.exit_code.is_none() {
+            if let Some(child) = self.child.as_mut() {
+                if let Ok(Some(status)) = child.try_wait() {
+                    self.exit_code = Some(status.code().unwrap_or(-1));
+                }
+            }
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        self.exit_code.is_none()
+    }
+
+    fn kill(&mut self) {
+        if let Some(child) = self.child.as_mut() {
+            let _ = child.kill();
+        }
+    }
 }
 
 struct App {
     step: Step,
     mode: SourceMode,
-    src_path: String,
-    yt_url: String,
+    src_path: TextField,
+    yt_url: TextField,
+    yt_probe: YtProbeState,
+    yt_probed_url: String,
+    yt_probe_rx: Option<Receiver<Result<YtInfo, String>>>,
+    /// Download every entry of a detected playlist instead of just the
+    /// first video. Only toggleable once a probe reports `is_playlist`.
+    batch_mode: bool,
     lang_index: usize,
+    lang_query: String,
+    lang_filtered: Vec<usize>,
+    lang_cursor: usize,
     overwrite: bool,
     burn_in: bool,
     burn_use: BurnUse,
     burn_format: BurnFormat,
+    audio_format: Option<AudioFormat>,
     should_exit: bool,
     generated_command: Option<String>,
+    run: Option<RunState>,
+    profiles: Vec<Profile>,
+    profile_cursor: usize,
+    saving_profile_name: Option<String>,
+    status_message: Option<String>,
 }
 
 impl App {
     fn new() -> Self {
+        let lang_index = LANG_OPTIONS
+            .iter()
+            .position(|(label, _)| *label == "Chinese (Simplified)")
+            .unwrap_or(0);
+        let lang_filtered = filter_langs("");
+        let lang_cursor = lang_filtered
+            .iter()
+            .position(|&i| i == lang_index)
+            .unwrap_or(0);
+
         Self {
             step: Step::Mode,
             mode: SourceMode::Local,
-            src_path: String::from("videos"),
-            yt_url: String::new(),
-            lang_index: 0, // Chinese by default
+            src_path: TextField::new("videos"),
+            yt_url: TextField::new(""),
+            yt_probe: YtProbeState::Idle,
+            yt_probed_url: String::new(),
+            yt_probe_rx: None,
+            batch_mode: false,
+            lang_index,
+            lang_query: String::new(),
+            lang_filtered,
+            lang_cursor,
             overwrite: false,
             burn_in: false,
             burn_use: BurnUse::Translated,
             burn_format: BurnFormat::Mp4,
+            audio_format: None,
             should_exit: false,
             generated_command: None,
+            run: None,
+            profiles: Vec::new(),
+            profile_cursor: 0,
+            saving_profile_name: None,
+            status_message: None,
+        }
+    }
+
+    /// Load any saved profiles, preload the most recently used one, and
+    /// park on `Step::ProfilePicker` if there is more than one to choose
+    /// from.
+    fn load_profiles(&mut self) {
+        let profiles = profile::load_profiles();
+        if profiles.is_empty() {
+            return;
+        }
+        let mru = profile::most_recently_used(&profiles).unwrap_or(0);
+        self.apply_profile(&profiles[mru]);
+        self.profile_cursor = mru;
+        if profiles.len() > 1 {
+            self.step = Step::ProfilePicker;
+        }
+        self.profiles = profiles;
+    }
+
+    fn apply_profile(&mut self, profile: &Profile) {
+        self.mode = profile.mode;
+        self.src_path = TextField::new(profile.src_path.clone());
+        self.lang_index = profile.lang_index.min(LANG_OPTIONS.len().saturating_sub(1));
+        self.overwrite = profile.overwrite;
+        self.burn_in = profile.burn_in;
+        self.burn_use = profile.burn_use;
+        self.burn_format = profile.burn_format;
+        self.lang_filtered = filter_langs(&self.lang_query);
+        self.lang_cursor = self
+            .lang_filtered
+            .iter()
+            .position(|&i| i == self.lang_index)
+            .unwrap_or(0);
+    }
+
+    fn snapshot_profile(&self, name: String) -> Profile {
+        Profile {
+            name,
+            mode: self.mode,
+            src_path: self.src_path.value(),
+            lang_index: self.lang_index,
+            overwrite: self.overwrite,
+            burn_in: self.burn_in,
+            burn_use: self.burn_use,
+            burn_format: self.burn_format,
+            last_used: profile::now_unix(),
+        }
+    }
+
+    /// Copy the currently generated command to the clipboard and flash a
+    /// transient confirmation in the hint area.
+    fn copy_command(&mut self) {
+        let command = self.build_command();
+        self.status_message = Some(match copy_to_clipboard(&command) {
+            Ok(()) => "Copied!".to_string(),
+            Err(_) => "Clipboard unavailable — command not copied".to_string(),
+        });
+    }
+
+    fn save_current_profile(&mut self, name: String) {
+        let snapshot = self.snapshot_profile(name.clone());
+        match profile::save(self.profiles.clone(), snapshot) {
+            Ok(()) => {
+                self.profiles = profile::load_profiles();
+                self.status_message = Some(format!("Saved profile '{}'", name));
+            }
+            Err(err) => {
+                self.status_message = Some(format!("Failed to save profile: {}", err));
+            }
+        }
+    }
+
+    /// Recompute `lang_filtered` from `lang_query`, keeping the cursor on
+    /// the same underlying entry if it is still present, else moving it to
+    /// the top of the list.
+    fn refilter_langs(&mut self) {
+        let previous = self.lang_filtered.get(self.lang_cursor).copied();
+        self.lang_filtered = filter_langs(&self.lang_query);
+        self.lang_cursor = previous
+            .and_then(|idx| self.lang_filtered.iter().position(|&i| i == idx))
+            .unwrap_or(0)
+            .min(self.lang_filtered.len().saturating_sub(1));
+        if let Some(&idx) = self.lang_filtered.get(self.lang_cursor) {
+            self.lang_index = idx;
+        }
+    }
+
+    /// Kick off a metadata probe for the URL currently in `yt_url`, unless
+    /// one is already in flight.
+    fn start_yt_probe(&mut self) {
+        if matches!(self.yt_probe, YtProbeState::Fetching) {
+            return;
+        }
+        let url = self.yt_url.value().trim().to_string();
+        if url.is_empty() {
+            return;
+        }
+        self.yt_probe = YtProbeState::Fetching;
+        self.yt_probed_url = url.clone();
+        self.yt_probe_rx = Some(spawn_probe(url));
+    }
+
+    /// Drain the probe channel, if any, applying a finished result.
+    fn poll_yt_probe(&mut self) {
+        let Some(rx) = self.yt_probe_rx.as_ref() else {
+            return;
+        };
+        if let Ok(result) = rx.try_recv() {
+            self.yt_probe = match result {
+                Ok(info) => YtProbeState::Ready(info),
+                Err(err) => YtProbeState::Error(err),
+            };
+            self.yt_probe_rx = None;
         }
     }
 
     fn next_step(&mut self) {
         self.step = match self.step {
+            Step::ProfilePicker => Step::Mode,
             Step::Mode => Step::SrcPath,
             Step::SrcPath => match self.mode {
                 SourceMode::Local => Step::Lang,
@@ -108,17 +374,27 @@ impl App {
                 if self.burn_in {
                     Step::BurnUse
                 } else {
-                    Step::Summary
+                    Step::AudioExtract
                 }
             }
             Step::BurnUse => Step::BurnFormat,
-            Step::BurnFormat => Step::Summary,
+            Step::BurnFormat => Step::AudioExtract,
+            Step::AudioExtract => {
+                if self.audio_format.is_some() {
+                    Step::AudioFormat
+                } else {
+                    Step::Summary
+                }
+            }
+            Step::AudioFormat => Step::Summary,
             Step::Summary => Step::Summary,
+            Step::Running => Step::Running,
         };
     }
 
     fn prev_step(&mut self) {
         self.step = match self.step {
+            Step::ProfilePicker => Step::ProfilePicker,
             Step::Mode => Step::Mode,
             Step::SrcPath => Step::Mode,
             Step::YtUrl => Step::SrcPath,
@@ -130,17 +406,36 @@ impl App {
             Step::BurnIn => Step::Overwrite,
             Step::BurnUse => Step::BurnIn,
             Step::BurnFormat => Step::BurnUse,
-            Step::Summary => {
+            Step::AudioExtract => {
                 if self.burn_in {
                     Step::BurnFormat
                 } else {
                     Step::BurnIn
                 }
             }
+            Step::AudioFormat => Step::AudioExtract,
+            Step::Summary => {
+                if self.audio_format.is_some() {
+                    Step::AudioFormat
+                } else {
+                    Step::AudioExtract
+                }
+            }
+            Step::Running => {
+                if self.run.as_ref().is_some_and(RunState::is_running) {
+                    Step::Running
+                } else {
+                    Step::Summary
+                }
+            }
         };
     }
 
-    fn build_command(&self) -> String {
+    /// Build the argv for the configured run: `parts[0]` is the program,
+    /// `parts[1..]` the arguments, each kept as a separate element (no
+    /// shell-style quoting) so it can be executed directly via
+    /// `Command::new(&parts[0]).args(&parts[1..])`.
+    fn command_parts(&self) -> Vec<String> {
         // Prefer `uv run subtitle-gen` if a pyproject is found upward from CWD.
         let py_dir = find_pyproject_dir();
         let program = if py_dir.is_some() {
@@ -158,15 +453,19 @@ impl App {
         match self.mode {
             SourceMode::Local => {
                 parts.push("--src".to_string());
-                parts.push(self.src_path.clone());
+                parts.push(self.src_path.value());
             }
             SourceMode::YouTube => {
-                if !self.yt_url.trim().is_empty() {
+                let yt_url = self.yt_url.value();
+                if !yt_url.trim().is_empty() {
                     parts.push("--yt".to_string());
-                    parts.push(format!("\"{}\"", self.yt_url.trim()));
+                    parts.push(yt_url.trim().to_string());
                 }
                 parts.push("--src".to_string());
-                parts.push(self.src_path.clone());
+                parts.push(self.src_path.value());
+                if self.batch_mode {
+                    parts.push("--batch".to_string());
+                }
             }
         }
 
@@ -200,13 +499,69 @@ impl App {
             );
         }
 
-        parts.join(" ")
+        if let Some(audio_format) = self.audio_format {
+            parts.push("--extract-audio".to_string());
+            parts.push("--audio-format".to_string());
+            parts.push(
+                match audio_format {
+                    AudioFormat::Mp3 => "mp3",
+                    AudioFormat::M4a => "m4a",
+                    AudioFormat::Wav => "wav",
+                }
+                .to_string(),
+            );
+        }
+
+        parts
+    }
+
+    /// Human-readable, copy-pasteable rendering of [`Self::command_parts`],
+    /// quoting any part a shell would otherwise split on. Only ever shown to
+    /// the user (Summary screen, clipboard, the saved run script) — never
+    /// fed back into a shell by this program itself.
+    fn build_command(&self) -> String {
+        self.command_parts()
+            .iter()
+            .map(|part| shell_quote_for_display(part))
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
     fn execute(&mut self) {
         self.generated_command = Some(self.build_command());
         self.should_exit = true;
     }
+
+    /// Spawn the generated command as a child process and switch to the
+    /// `Running` step to stream its output live.
+    fn run_now(&mut self) {
+        let parts = self.command_parts();
+        let display = self.build_command();
+        let dir = find_pyproject_dir();
+        match RunState::spawn(&parts, dir.as_ref()) {
+            Ok(run) => {
+                self.generated_command = Some(display);
+                self.run = Some(run);
+                self.step = Step::Running;
+            }
+            Err(err) => {
+                self.generated_command = Some(format!("{} (failed to start: {})", display, err));
+            }
+        }
+    }
+}
+
+/// Quote `part` for display/copy-paste into a POSIX shell if it contains
+/// anything a shell would treat specially; otherwise return it unchanged.
+fn shell_quote_for_display(part: &str) -> String {
+    let plain = part
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/' | '.' | ':' | '='));
+    if plain && !part.is_empty() {
+        part.to_string()
+    } else {
+        format!("'{}'", part.replace('\'', "'\\''"))
+    }
 }
 
 fn main() -> Result<()> {
@@ -234,9 +589,15 @@ fn main() -> Result<()> {
 
 fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
     let mut app = App::new();
+    app.load_profiles();
     let tick_rate = Duration::from_millis(100);
 
     loop {
+        if let Some(run) = app.run.as_mut() {
+            run.tick();
+        }
+        app.poll_yt_probe();
+
         terminal.draw(|f| ui(f, &mut app))?;
 
         if app.should_exit {
@@ -250,6 +611,10 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()>
         }
     }
 
+    if let Some(run) = app.run.as_mut() {
+        run.kill();
+    }
+
     // Simply display the generated command for manual execution
     if let Some(cmd) = app.generated_command {
         println!();
@@ -286,23 +651,66 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()>
             println!("   (Run from the project root directory)");
         }
         println!();
+
+        print!("Press c then Enter to copy it to the clipboard, or just Enter to skip: ");
+        io::Write::flush(&mut io::stdout()).ok();
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer).is_ok() && answer.trim() == "c" {
+            match copy_to_clipboard(&cmd) {
+                Ok(()) => println!("Copied to clipboard!"),
+                Err(err) => println!("Could not access the clipboard: {}", err),
+            }
+        }
     }
 
     Ok(())
 }
 
 fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
-    // Global quit
-    if key.code == KeyCode::Char('q') && key.modifiers.is_empty() {
+    // Global quit, except while a step is capturing free-text input that may
+    // itself contain the letter "q" (a profile name, a path or URL, or the
+    // language filter query) — otherwise typing it there silently discards
+    // the input.
+    let capturing_text = app.saving_profile_name.is_some()
+        || matches!(app.step, Step::Lang | Step::SrcPath | Step::YtUrl);
+    if key.code == KeyCode::Char('q') && key.modifiers.is_empty() && !capturing_text {
+        if let Some(run) = app.run.as_mut() {
+            run.kill();
+        }
         app.should_exit = true;
         return Ok(());
     }
     if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        if let Some(run) = app.run.as_mut() {
+            run.kill();
+        }
         app.should_exit = true;
         return Ok(());
     }
 
     match app.step {
+        Step::ProfilePicker => match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.profile_cursor = app.profile_cursor.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if app.profile_cursor + 1 < app.profiles.len() => {
+                app.profile_cursor += 1;
+            }
+            KeyCode::Enter => {
+                if let Some(profile) = app.profiles.get(app.profile_cursor).cloned() {
+                    app.apply_profile(&profile);
+                }
+                app.step = Step::Mode;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                // Start from the defaults instead of the preloaded profile.
+                let profiles = std::mem::take(&mut app.profiles);
+                *app = App::new();
+                app.profiles = profiles;
+                app.step = Step::Mode;
+            }
+            _ => {}
+        },
         Step::Mode => match key.code {
             KeyCode::Up | KeyCode::Char('k') => {
                 app.mode = SourceMode::Local;
@@ -317,20 +725,20 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
         },
         Step::SrcPath => match key.code {
             KeyCode::Enter => app.next_step(),
-            KeyCode::Backspace => {
-                app.src_path.pop();
-            }
-            KeyCode::Char('/') | KeyCode::Char('.') | KeyCode::Char('-') | KeyCode::Char('_') => {
-                app.src_path.push(match key.code {
-                    KeyCode::Char(c) => c,
-                    _ => unreachable!(),
-                });
-            }
-            KeyCode::Char(c) => {
-                if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                    app.src_path.push(c);
+            KeyCode::Backspace => app.src_path.backspace(),
+            KeyCode::Delete => app.src_path.delete_forward(),
+            KeyCode::Left => app.src_path.move_left(),
+            KeyCode::Right => app.src_path.move_right(),
+            KeyCode::Home => app.src_path.move_home(),
+            KeyCode::End => app.src_path.move_end(),
+            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(text) = read_clipboard_text() {
+                    app.src_path.insert_str(&text);
                 }
             }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.src_path.insert(c);
+            }
             KeyCode::Tab => {
                 app.next_step();
             }
@@ -340,16 +748,48 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
             _ => {}
         },
         Step::YtUrl => match key.code {
-            KeyCode::Enter => app.next_step(),
+            KeyCode::Enter => {
+                let probed = matches!(app.yt_probe, YtProbeState::Ready(_))
+                    && app.yt_probed_url == app.yt_url.value().trim();
+                if probed {
+                    app.next_step();
+                } else if !matches!(app.yt_probe, YtProbeState::Fetching) {
+                    app.start_yt_probe();
+                }
+            }
             KeyCode::Backspace => {
-                app.yt_url.pop();
+                app.yt_url.backspace();
+                app.yt_probe = YtProbeState::Idle;
+                app.batch_mode = false;
+            }
+            KeyCode::Delete => {
+                app.yt_url.delete_forward();
+                app.yt_probe = YtProbeState::Idle;
+                app.batch_mode = false;
             }
-            KeyCode::Char(c) => {
-                if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                    app.yt_url.push(c);
+            KeyCode::Left => app.yt_url.move_left(),
+            KeyCode::Right => app.yt_url.move_right(),
+            KeyCode::Home => app.yt_url.move_home(),
+            KeyCode::End => app.yt_url.move_end(),
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if matches!(&app.yt_probe, YtProbeState::Ready(info) if info.is_playlist) {
+                    app.batch_mode = !app.batch_mode;
                 }
             }
+            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(text) = read_clipboard_text() {
+                    app.yt_url.insert_str(&text);
+                    app.yt_probe = YtProbeState::Idle;
+                    app.batch_mode = false;
+                }
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.yt_url.insert(c);
+                app.yt_probe = YtProbeState::Idle;
+                app.batch_mode = false;
+            }
             KeyCode::Tab => {
+                // Skip validation and proceed with whatever was typed.
                 app.next_step();
             }
             KeyCode::Esc => {
@@ -358,16 +798,26 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
             _ => {}
         },
         Step::Lang => match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                if app.lang_index > 0 {
-                    app.lang_index -= 1;
+            KeyCode::Up if app.lang_cursor > 0 => {
+                app.lang_cursor -= 1;
+                if let Some(&idx) = app.lang_filtered.get(app.lang_cursor) {
+                    app.lang_index = idx;
                 }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if app.lang_index + 1 < LANG_OPTIONS.len() {
-                    app.lang_index += 1;
+            KeyCode::Down if app.lang_cursor + 1 < app.lang_filtered.len() => {
+                app.lang_cursor += 1;
+                if let Some(&idx) = app.lang_filtered.get(app.lang_cursor) {
+                    app.lang_index = idx;
                 }
             }
+            KeyCode::Backspace => {
+                app.lang_query.pop();
+                app.refilter_langs();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.lang_query.push(c);
+                app.refilter_langs();
+            }
             KeyCode::Enter | KeyCode::Tab => app.next_step(),
             KeyCode::Esc => app.prev_step(),
             _ => {}
@@ -398,13 +848,98 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
             KeyCode::Esc => app.prev_step(),
             _ => {}
         },
+        Step::AudioExtract => match key.code {
+            KeyCode::Char(' ') => {
+                app.audio_format = if app.audio_format.is_some() {
+                    None
+                } else {
+                    Some(AudioFormat::Mp3)
+                };
+            }
+            KeyCode::Enter | KeyCode::Tab => app.next_step(),
+            KeyCode::Esc => app.prev_step(),
+            _ => {}
+        },
+        Step::AudioFormat => match key.code {
+            KeyCode::Left | KeyCode::Char('h') => {
+                app.audio_format = Some(match app.audio_format.unwrap_or(AudioFormat::Mp3) {
+                    AudioFormat::Mp3 => AudioFormat::Wav,
+                    AudioFormat::M4a => AudioFormat::Mp3,
+                    AudioFormat::Wav => AudioFormat::M4a,
+                });
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                app.audio_format = Some(match app.audio_format.unwrap_or(AudioFormat::Mp3) {
+                    AudioFormat::Mp3 => AudioFormat::M4a,
+                    AudioFormat::M4a => AudioFormat::Wav,
+                    AudioFormat::Wav => AudioFormat::Mp3,
+                });
+            }
+            KeyCode::Enter | KeyCode::Tab => app.next_step(),
+            KeyCode::Esc => app.prev_step(),
+            _ => {}
+        },
+        Step::Summary if app.saving_profile_name.is_some() => match key.code {
+            KeyCode::Enter => {
+                if let Some(name) = app.saving_profile_name.take() {
+                    if !name.trim().is_empty() {
+                        app.save_current_profile(name.trim().to_string());
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(name) = app.saving_profile_name.as_mut() {
+                    name.pop();
+                }
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(name) = app.saving_profile_name.as_mut() {
+                    name.push(c);
+                }
+            }
+            KeyCode::Esc => {
+                app.saving_profile_name = None;
+            }
+            _ => {}
+        },
         Step::Summary => match key.code {
-            KeyCode::Char('r') | KeyCode::Enter => {
-                app.execute();
+            KeyCode::Char('r') => app.run_now(),
+            KeyCode::Char('s') => {
+                app.saving_profile_name = Some(String::new());
+                app.status_message = None;
             }
+            KeyCode::Char('c') => app.copy_command(),
+            KeyCode::Enter => app.execute(),
             KeyCode::Esc => app.prev_step(),
             _ => {}
         },
+        Step::Running => {
+            let running = app.run.as_ref().is_some_and(RunState::is_running);
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if let Some(run) = app.run.as_mut() {
+                        run.scroll = run.scroll.saturating_sub(1);
+                    }
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if let Some(run) = app.run.as_mut() {
+                        run.scroll += 1;
+                    }
+                }
+                KeyCode::PageUp => {
+                    if let Some(run) = app.run.as_mut() {
+                        run.scroll = run.scroll.saturating_sub(10);
+                    }
+                }
+                KeyCode::PageDown => {
+                    if let Some(run) = app.run.as_mut() {
+                        run.scroll += 10;
+                    }
+                }
+                KeyCode::Esc if !running => app.prev_step(),
+                _ => {}
+            }
+        }
     }
 
     Ok(())
@@ -430,6 +965,7 @@ fn ui(f: &mut ratatui::Frame, app: &mut App) {
     f.render_widget(Paragraph::new(header), chunks[0]);
 
     match app.step {
+        Step::ProfilePicker => render_profile_picker(f, chunks[1], app),
         Step::Mode => render_mode(f, chunks[1], app),
         Step::SrcPath => render_src_path(f, chunks[1], app),
         Step::YtUrl => render_yt_url(f, chunks[1], app),
@@ -438,10 +974,31 @@ fn ui(f: &mut ratatui::Frame, app: &mut App) {
         Step::BurnIn => render_burnin(f, chunks[1], app),
         Step::BurnUse => render_burn_use(f, chunks[1], app),
         Step::BurnFormat => render_burn_format(f, chunks[1], app),
+        Step::AudioExtract => render_audio_extract(f, chunks[1], app),
+        Step::AudioFormat => render_audio_format(f, chunks[1], app),
         Step::Summary => render_summary(f, chunks[1], app),
+        Step::Running => render_running(f, chunks[1], app),
     }
 }
 
+fn render_profile_picker(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let mut lines = vec![q_line("Load a saved profile?"), Line::from("")];
+    for (i, profile) in app.profiles.iter().enumerate() {
+        let label = format!("{} (last used {})", profile.name, profile.last_used);
+        if i == app.profile_cursor {
+            lines.push(Line::from(vec![
+                Span::styled("› ", Style::default().fg(Color::Yellow)),
+                Span::raw(label),
+            ]));
+        } else {
+            lines.push(Line::from(format!("  {}", label)));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(hint_line(app));
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
+}
+
 fn render_mode(f: &mut ratatui::Frame, area: Rect, app: &App) {
     let mut lines = Vec::new();
     lines.push(q_line("How do you want to get videos?"));
@@ -466,20 +1023,32 @@ fn render_mode(f: &mut ratatui::Frame, area: Rect, app: &App) {
     f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
 }
 
+/// Render a [`TextField`] as `prefix` followed by its text, with the
+/// character under the caret highlighted as a block cursor.
+fn text_field_line(prefix: &str, field: &TextField) -> Line<'static> {
+    let (before, at, after) = field.split_at_caret();
+    let mut spans = vec![
+        Span::raw(prefix.to_string()),
+        Span::styled(before, Style::default().fg(Color::Green)),
+    ];
+    spans.push(Span::styled(
+        at.map(String::from).unwrap_or_else(|| " ".to_string()),
+        Style::default().fg(Color::Black).bg(Color::Green),
+    ));
+    spans.push(Span::styled(after, Style::default().fg(Color::Green)));
+    Line::from(spans)
+}
+
 fn render_src_path(f: &mut ratatui::Frame, area: Rect, app: &App) {
     let title = match app.mode {
         SourceMode::Local => "Where are the videos located?",
         SourceMode::YouTube => "Where should downloaded videos be saved?",
     };
-    let lines = vec![
+    let mut lines = vec![
         q_line(title),
         Line::from(""),
-        Line::from(vec![
-            Span::raw("Path: "),
-            Span::styled(&app.src_path, Style::default().fg(Color::Green)),
-        ]),
+        text_field_line("Path: ", &app.src_path),
     ];
-    let mut lines = lines;
     lines.push(Line::from(""));
     lines.push(hint_line(app));
     f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
@@ -489,41 +1058,123 @@ fn render_yt_url(f: &mut ratatui::Frame, area: Rect, app: &App) {
     let mut lines = vec![
         q_line("Paste a YouTube URL"),
         Line::from(""),
-        Line::from(vec![
-            Span::raw("URL: "),
-            Span::styled(&app.yt_url, Style::default().fg(Color::Green)),
-        ]),
+        text_field_line("URL: ", &app.yt_url),
+        Line::from(""),
     ];
+
+    match &app.yt_probe {
+        YtProbeState::Idle => {
+            lines.push(Line::from(Span::styled(
+                "Press Enter to fetch video details.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        YtProbeState::Fetching => {
+            lines.push(Line::from(Span::styled(
+                "Fetching…",
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+        YtProbeState::Error(message) => {
+            lines.push(Line::from(Span::styled(
+                format!("Could not fetch video info: {}", message),
+                Style::default().fg(Color::Red),
+            )));
+        }
+        YtProbeState::Ready(info) => {
+            lines.push(Line::from(vec![
+                Span::raw("Title: "),
+                Span::styled(&info.title, Style::default().fg(Color::Cyan)),
+            ]));
+            lines.push(Line::from(format!("Uploader: {}", info.uploader)));
+            if let Some(duration) = info.duration_secs {
+                lines.push(Line::from(format!(
+                    "Duration: {}:{:02}",
+                    duration as u64 / 60,
+                    duration as u64 % 60
+                )));
+            }
+            if info.is_playlist {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "Playlist detected ({} entries). Ctrl+B to {} batch mode.",
+                        info.entry_count.unwrap_or(0),
+                        if app.batch_mode { "disable" } else { "enable" },
+                    ),
+                    Style::default().fg(Color::Magenta),
+                )));
+                if app.batch_mode {
+                    lines.push(Line::from(Span::styled(
+                        "Batch mode on: every entry in the playlist will be downloaded.",
+                        Style::default().fg(Color::Magenta),
+                    )));
+                }
+            }
+            if info.has_existing_captions() {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "Existing captions found: {}. Transcription may be skippable.",
+                        info.existing_captions.join(", ")
+                    ),
+                    Style::default().fg(Color::Green),
+                )));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from("Press Enter again to continue."));
+        }
+    }
+
     lines.push(Line::from(""));
     lines.push(hint_line(app));
     f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
 }
 
 fn render_lang(f: &mut ratatui::Frame, area: Rect, app: &App) {
-    let lines_top = vec![q_line("What's the target language?"), Line::from("")];
+    let query_display = if app.lang_query.is_empty() {
+        Span::styled(
+            "(type to filter…)",
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        Span::styled(app.lang_query.as_str(), Style::default().fg(Color::Green))
+    };
+    let lines_top = vec![
+        q_line("What's the target language?"),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Filter: "),
+            query_display,
+            Span::styled(
+                format!("  ({} match{})", app.lang_filtered.len(), if app.lang_filtered.len() == 1 { "" } else { "es" }),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+    ];
     f.render_widget(
         Paragraph::new(lines_top),
         Rect {
             x: area.x,
             y: area.y,
             width: area.width,
-            height: 2,
+            height: 3,
         },
     );
     // leave 2 lines at bottom for blank + hint
     let list_area = Rect {
         x: area.x,
-        y: area.y + 2,
+        y: area.y + 3,
         width: area.width,
-        height: area.height.saturating_sub(4),
+        height: area.height.saturating_sub(5),
     };
-    let items: Vec<ListItem> = LANG_OPTIONS
+    let items: Vec<ListItem> = app
+        .lang_filtered
         .iter()
         .enumerate()
-        .map(|(i, (label, code))| {
+        .map(|(cursor, &idx)| {
+            let (label, code) = LANG_OPTIONS[idx];
             let line = Line::from(format!("{} ({})", label, code));
             let mut item = ListItem::new(line);
-            if i == app.lang_index {
+            if cursor == app.lang_cursor {
                 item = item.style(Style::default().fg(Color::Yellow));
             }
             item
@@ -546,9 +1197,29 @@ fn render_lang(f: &mut ratatui::Frame, area: Rect, app: &App) {
 }
 
 fn find_pyproject_dir() -> Option<PathBuf> {
+    find_upward_dir("pyproject.toml")
+}
+
+/// Copy `text` to the system clipboard. Fails gracefully (no panic) on
+/// headless systems or other environments without clipboard access.
+fn copy_to_clipboard(text: &str) -> std::result::Result<(), String> {
+    Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text.to_string()))
+        .map_err(|err| err.to_string())
+}
+
+/// Read the system clipboard as text, swallowing errors on headless
+/// systems or when the clipboard holds something other than text.
+fn read_clipboard_text() -> Option<String> {
+    Clipboard::new().ok()?.get_text().ok()
+}
+
+/// Look for `filename` in the CWD and up to 4 directories above it,
+/// returning the directory it was found in.
+pub(crate) fn find_upward_dir(filename: &str) -> Option<PathBuf> {
     let mut cur = std::env::current_dir().ok()?;
     for _ in 0..5 {
-        let candidate = cur.join("pyproject.toml");
+        let candidate = cur.join(filename);
         if candidate.exists() {
             return Some(cur);
         }
@@ -644,6 +1315,45 @@ fn render_burn_format(f: &mut ratatui::Frame, area: Rect, app: &App) {
     f.render_widget(Paragraph::new(lines), area);
 }
 
+fn render_audio_extract(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let enabled = app.audio_format.is_some();
+    let text = if enabled { "Yes" } else { "No" };
+    let mut lines = vec![
+        q_line("Also extract an audio-only track?"),
+        Line::from(""),
+        Line::from(Span::styled(
+            text,
+            Style::default().fg(if enabled { Color::Green } else { Color::Gray }),
+        )),
+    ];
+    lines.push(Line::from(""));
+    lines.push(hint_line(app));
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_audio_format(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let mut lines = vec![q_line("What audio format?"), Line::from("")];
+    let opts = ["MP3", "M4A", "WAV"];
+    let idx = match app.audio_format.unwrap_or(AudioFormat::Mp3) {
+        AudioFormat::Mp3 => 0,
+        AudioFormat::M4a => 1,
+        AudioFormat::Wav => 2,
+    };
+    for (i, opt) in opts.iter().enumerate() {
+        if i == idx {
+            lines.push(Line::from(vec![
+                Span::styled("› ", Style::default().fg(Color::Yellow)),
+                Span::raw(*opt),
+            ]));
+        } else {
+            lines.push(Line::from(format!("  {}", opt)));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(hint_line(app));
+    f.render_widget(Paragraph::new(lines), area);
+}
+
 fn render_summary(f: &mut ratatui::Frame, area: Rect, app: &App) {
     let command = app.build_command();
     let mut lines = vec![
@@ -656,26 +1366,120 @@ fn render_summary(f: &mut ratatui::Frame, area: Rect, app: &App) {
         Line::from(""),
         Line::from(Span::styled(command, Style::default().fg(Color::Cyan))),
         Line::from(""),
-        Line::from("Press Enter to generate command and exit"),
+        Line::from("Press r to run now, or Enter to print the command and exit"),
     ];
+
+    if let Some(name) = &app.saving_profile_name {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw("Save as profile: "),
+            Span::styled(name, Style::default().fg(Color::Green)),
+            Span::styled("_", Style::default().fg(Color::DarkGray)),
+        ]));
+        lines.push(Line::from("Enter to confirm, Esc to cancel"));
+    } else if let Some(message) = &app.status_message {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            message.as_str(),
+            Style::default().fg(Color::Green),
+        )));
+    }
+
     lines.push(Line::from(""));
     lines.push(hint_line(app));
     f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
 }
 
+fn render_running(f: &mut ratatui::Frame, area: Rect, app: &App) {
+    let Some(run) = app.run.as_ref() else {
+        return;
+    };
+
+    let elapsed = run.started_at.elapsed().as_secs_f64();
+    let status_line = match run.exit_code {
+        None => Line::from(vec![
+            Span::styled(
+                format!("{} ", SPINNER_FRAMES[run.spinner_frame]),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(format!("Running… {:.1}s elapsed", elapsed)),
+        ]),
+        Some(0) => Line::from(Span::styled(
+            format!("✓ Finished successfully in {:.1}s", elapsed),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Some(code) => Line::from(Span::styled(
+            format!("✗ Exited with code {} after {:.1}s", code, elapsed),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+    };
+
+    let header_area = Rect {
+        x: area.x,
+        y: area.y,
+        width: area.width,
+        height: 2,
+    };
+    f.render_widget(
+        Paragraph::new(vec![status_line, Line::from("")]),
+        header_area,
+    );
+
+    let log_area = Rect {
+        x: area.x,
+        y: area.y + 2,
+        width: area.width,
+        height: area.height.saturating_sub(4),
+    };
+    let visible = log_area.height as usize;
+    let max_scroll = run.lines.len().saturating_sub(visible);
+    let scroll = run.scroll.min(max_scroll);
+    let items: Vec<ListItem> = run
+        .lines
+        .iter()
+        .skip(scroll)
+        .take(visible)
+        .map(|line| ListItem::new(Line::from(line.clone())))
+        .collect();
+    f.render_widget(List::new(items), log_area);
+
+    let hint_area = Rect {
+        x: area.x,
+        y: area.bottom().saturating_sub(2),
+        width: area.width,
+        height: 2,
+    };
+    f.render_widget(
+        Paragraph::new(vec![Line::from(""), hint_line(app)]).wrap(Wrap { trim: true }),
+        hint_area,
+    );
+}
+
 fn hint_line(app: &App) -> Line<'static> {
     let text = match app.step {
+        Step::ProfilePicker => "Up/Down to choose. Enter to load. n for a new profile.",
         Step::Mode => "Use Up/Down to choose. Enter to continue. Ctrl+C to quit.",
-        Step::SrcPath => "Type to edit. Enter to continue. Esc to go back. Ctrl+C to quit.",
-        Step::YtUrl => "Enter to continue. Esc to go back. Ctrl+C to quit.",
-        Step::Lang => "Use Up/Down to select. Enter to continue. Esc to go back. Ctrl+C to quit.",
+        Step::SrcPath => {
+            "Type to edit, Left/Right/Home/End to move, Ctrl+V to paste. Enter to continue."
+        }
+        Step::YtUrl => {
+            "Type or Ctrl+V to paste, Enter to fetch details, Enter again to continue."
+        }
+        Step::Lang => "Type to filter, Up/Down to select. Enter to continue. Esc to go back.",
         Step::Overwrite => "Space to toggle. Enter to continue. Esc to go back. Ctrl+C to quit.",
         Step::BurnIn => "Space to toggle. Enter to continue. Esc to go back. Ctrl+C to quit.",
         Step::BurnUse => "Left/Right to choose. Enter to continue. Esc to go back. Ctrl+C to quit.",
         Step::BurnFormat => {
             "Left/Right to choose. Enter to continue. Esc to go back. Ctrl+C to quit."
         }
-        Step::Summary => "Press Enter to generate and exit. Esc to go back. Ctrl+C to quit.",
+        Step::AudioExtract => "Space to toggle. Enter to continue. Esc to go back. Ctrl+C to quit.",
+        Step::AudioFormat => {
+            "Left/Right to choose. Enter to continue. Esc to go back. Ctrl+C to quit."
+        }
+        Step::Summary => "r to run, c to copy, s to save as a profile, Enter to print and exit.",
+        Step::Running => "Up/Down or PageUp/PageDown to scroll. Esc to go back when finished.",
     };
     Line::from(Span::styled(text, Style::default().fg(Color::DarkGray)))
 }
@@ -0,0 +1,141 @@
+//! Persisted wizard answers, so repeat runs don't start from scratch.
+//!
+//! Profiles live in `subtitle-tui.toml`, searched for upward from the CWD
+//! the same way [`crate::find_upward_dir`] locates `pyproject.toml`,
+//! falling back to the OS config directory. This mirrors how render
+//! pipelines keep a TOML project file describing the chosen parameters.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BurnFormat, BurnUse, SourceMode};
+
+const CONFIG_FILE_NAME: &str = "subtitle-tui.toml";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub mode: SourceMode,
+    pub src_path: String,
+    pub lang_index: usize,
+    pub overwrite: bool,
+    pub burn_in: bool,
+    pub burn_use: BurnUse,
+    pub burn_format: BurnFormat,
+    /// Unix timestamp (seconds) of the last time this profile was saved.
+    pub last_used: u64,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct ProfileFile {
+    #[serde(default)]
+    profile: Vec<Profile>,
+}
+
+/// Path to an existing `subtitle-tui.toml`, if one is found upward from
+/// the CWD.
+fn existing_config_path() -> Option<PathBuf> {
+    crate::find_upward_dir(CONFIG_FILE_NAME).map(|dir| dir.join(CONFIG_FILE_NAME))
+}
+
+fn config_dir_fallback() -> PathBuf {
+    if let Ok(dir) = std::env::var("XDG_CONFIG_HOME") {
+        return PathBuf::from(dir).join("subtitle-tui");
+    }
+    if let Ok(home) = std::env::var("HOME") {
+        return PathBuf::from(home).join(".config").join("subtitle-tui");
+    }
+    PathBuf::from(".")
+}
+
+/// Where a new profile should be written: next to an existing config file
+/// if one was found, otherwise the OS config directory.
+fn save_path() -> PathBuf {
+    existing_config_path().unwrap_or_else(|| config_dir_fallback().join(CONFIG_FILE_NAME))
+}
+
+pub fn load_profiles() -> Vec<Profile> {
+    let path = existing_config_path().unwrap_or_else(|| config_dir_fallback().join(CONFIG_FILE_NAME));
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    toml::from_str::<ProfileFile>(&contents)
+        .map(|f| f.profile)
+        .unwrap_or_default()
+}
+
+/// Index of the most recently used profile, if any.
+pub fn most_recently_used(profiles: &[Profile]) -> Option<usize> {
+    profiles
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, p)| p.last_used)
+        .map(|(i, _)| i)
+}
+
+pub fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Upsert `profile` (matched by name) into the saved config and write it
+/// back out, creating the parent directory if needed.
+pub fn save(mut profiles: Vec<Profile>, profile: Profile) -> io::Result<()> {
+    if let Some(existing) = profiles.iter_mut().find(|p| p.name == profile.name) {
+        *existing = profile;
+    } else {
+        profiles.push(profile);
+    }
+
+    let path = save_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let toml = toml::to_string_pretty(&ProfileFile { profile: profiles })
+        .map_err(io::Error::other)?;
+    std::fs::write(path, toml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `save`/`load_profiles` read XDG_CONFIG_HOME, a process-global, so
+    // serialize tests that set it to avoid them stomping on each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn save_then_load_round_trips_through_the_config_dir_fallback() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("subtitle-tui-test-{}", std::process::id()));
+        std::env::set_var("XDG_CONFIG_HOME", &dir);
+
+        let profile = Profile {
+            name: "demo".to_string(),
+            mode: SourceMode::Local,
+            src_path: "videos".to_string(),
+            lang_index: 0,
+            overwrite: false,
+            burn_in: false,
+            burn_use: BurnUse::Translated,
+            burn_format: BurnFormat::Mp4,
+            last_used: 42,
+        };
+        save(Vec::new(), profile).expect("save should succeed");
+
+        let loaded = load_profiles();
+
+        std::fs::remove_dir_all(&dir).ok();
+        std::env::remove_var("XDG_CONFIG_HOME");
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "demo");
+        assert_eq!(loaded[0].last_used, 42);
+    }
+}
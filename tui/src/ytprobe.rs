@@ -0,0 +1,107 @@
+//! Background metadata probing for `Step::YtUrl`, backed by `yt-dlp` via
+//! the `youtube_dl` crate. Extraction runs on a worker thread so the
+//! 100ms draw loop never blocks on the network.
+
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use youtube_dl::{SingleVideo, YoutubeDl, YoutubeDlOutput};
+
+/// Metadata shown in the preview panel once a probe completes.
+pub struct YtInfo {
+    pub title: String,
+    pub uploader: String,
+    pub duration_secs: Option<f64>,
+    pub existing_captions: Vec<String>,
+    pub is_playlist: bool,
+    pub entry_count: Option<usize>,
+}
+
+impl YtInfo {
+    pub fn has_existing_captions(&self) -> bool {
+        !self.existing_captions.is_empty()
+    }
+}
+
+/// Probe lifecycle for the URL currently entered in `Step::YtUrl`.
+pub enum YtProbeState {
+    /// No probe has run for the current URL yet.
+    Idle,
+    /// A probe is running on a worker thread.
+    Fetching,
+    /// The probe finished successfully.
+    Ready(YtInfo),
+    /// The probe failed; the message is shown inline and the user stays
+    /// on the step.
+    Error(String),
+}
+
+/// Kick off a background `yt-dlp --dump-json` probe for `url` and return a
+/// receiver that yields exactly one result once it completes.
+pub fn spawn_probe(url: String) -> Receiver<Result<YtInfo, String>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let result = probe(&url).map_err(|err| err.to_string());
+        let _ = tx.send(result);
+    });
+    rx
+}
+
+fn probe(url: &str) -> Result<YtInfo, youtube_dl::Error> {
+    let output = YoutubeDl::new(url)
+        .flat_playlist(true)
+        .socket_timeout("15")
+        .run()?;
+
+    Ok(match output {
+        YoutubeDlOutput::SingleVideo(video) => YtInfo {
+            title: video.title.unwrap_or_default(),
+            uploader: video.uploader.unwrap_or_default(),
+            duration_secs: video.duration.and_then(|d| d.as_f64()),
+            existing_captions: video
+                .subtitles
+                .as_ref()
+                .map(|subs| subs.keys().cloned().collect())
+                .unwrap_or_default(),
+            is_playlist: false,
+            entry_count: None,
+        },
+        YoutubeDlOutput::Playlist(playlist) => {
+            let entries = playlist.entries.unwrap_or_default();
+            let entry_count = Some(entries.len());
+            let existing_captions = entries
+                .first()
+                .and_then(probe_first_entry_captions)
+                .unwrap_or_default();
+
+            YtInfo {
+                title: playlist.title.unwrap_or_default(),
+                uploader: playlist.uploader.unwrap_or_default(),
+                duration_secs: None,
+                existing_captions,
+                is_playlist: true,
+                entry_count,
+            }
+        }
+    })
+}
+
+/// `flat_playlist` entries carry only minimal metadata (id/title/url) and
+/// no subtitle info, so caption detection for a playlist needs a second,
+/// non-flat probe of just the first entry. Best-effort: any failure here
+/// just means no captions are reported, not that the whole probe fails.
+fn probe_first_entry_captions(first: &SingleVideo) -> Option<Vec<String>> {
+    let entry_url = first
+        .webpage_url
+        .clone()
+        .or_else(|| first.url.clone())
+        .unwrap_or_else(|| format!("https://www.youtube.com/watch?v={}", first.id));
+
+    let output = YoutubeDl::new(entry_url).socket_timeout("15").run().ok()?;
+    match output {
+        YoutubeDlOutput::SingleVideo(video) => {
+            video.subtitles.map(|subs| subs.keys().cloned().collect())
+        }
+        YoutubeDlOutput::Playlist(_) => None,
+    }
+}